@@ -0,0 +1,53 @@
+use clap::Parser;
+
+use crate::{
+    data_storage::validation::ContentTypeValidator,
+    protocol::{core::checksum::ChecksumAlgorithm, extensions::Extensions},
+};
+
+/// Runtime configuration, parsed from CLI flags and environment variables.
+///
+/// Only the knobs touched by the storage/validation subsystems are shown here;
+/// the rest of the surface is unchanged.
+#[derive(Parser, Clone, Debug)]
+pub struct Config {
+    /// Enabled TUS protocol extensions.
+    #[arg(long, env = "RUSTUS_TUS_EXTENSIONS", value_delimiter = ',')]
+    pub tus_extensions: Vec<Extensions>,
+
+    /// Allowlist of accepted content types, matched against the type sniffed
+    /// from an upload's leading bytes (e.g. `image/jpeg,application/pdf`).
+    ///
+    /// Empty (the default) disables content-type validation entirely.
+    #[arg(long, env = "RUSTUS_ALLOWED_TYPES", value_delimiter = ',', default_value = "")]
+    pub allowed_types: Vec<String>,
+
+    /// Checksum algorithms advertised through `Tus-Checksum-Algorithm` and
+    /// accepted for `Upload-Checksum` verification.
+    ///
+    /// Defaults to the historic set; operators can drop weak algorithms
+    /// (`md5`, `sha1`) or add `crc32c`/`blake3`.
+    #[arg(
+        long,
+        env = "RUSTUS_CHECKSUM_ALGORITHMS",
+        value_delimiter = ',',
+        default_value = "md5,sha1,sha256,sha512"
+    )]
+    pub checksum_algorithms: Vec<ChecksumAlgorithm>,
+}
+
+impl Config {
+    /// Build the content-type validator from the configured allowlist.
+    #[must_use]
+    pub fn content_type_validator(&self) -> ContentTypeValidator {
+        // An empty default parses to a single empty entry; drop such entries so
+        // the validator stays disabled.
+        ContentTypeValidator::new(
+            self.allowed_types
+                .iter()
+                .filter(|allowed| !allowed.is_empty())
+                .cloned()
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,64 @@
+use axum::http::HeaderMap;
+use axum::response::Response;
+use bytes::Bytes;
+
+use crate::{errors::RustusResult, models::file_info::FileInfo};
+
+/// Common interface implemented by every storage backend.
+///
+/// Backends persist the raw bytes of an upload; all TUS bookkeeping lives in
+/// the info storage layer and is threaded in through [`FileInfo`].
+pub trait Storage {
+    /// Human-readable backend name, surfaced in logs and metrics.
+    fn get_name(&self) -> &'static str;
+
+    /// Prepare the backend for use (open connections, create directories, ...).
+    ///
+    /// # Errors
+    ///
+    /// Fails if the backend cannot be initialised.
+    async fn prepare(&mut self) -> RustusResult<()>;
+
+    /// Stream the contents of a completed upload back to the client.
+    ///
+    /// `headers` are the request headers, so a backend can honour a `Range`
+    /// request and answer with `206 Partial Content`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file is missing or cannot be read.
+    async fn get_contents(&self, file_info: &FileInfo, headers: &HeaderMap)
+        -> RustusResult<Response>;
+
+    /// Persist the next chunk of an upload at its current offset.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the bytes cannot be written.
+    async fn add_bytes(&self, file_info: &FileInfo, bytes: Bytes) -> RustusResult<()>;
+
+    /// Create the (empty) backing file for a freshly created upload.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file cannot be created.
+    async fn create_file(&self, file_info: &FileInfo) -> RustusResult<String>;
+
+    /// Concatenate `parts_info` into `file_info` for the Concatenation extension.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any part is missing or the join cannot be written.
+    async fn concat_files(
+        &self,
+        file_info: &FileInfo,
+        parts_info: Vec<FileInfo>,
+    ) -> RustusResult<()>;
+
+    /// Remove the backing file of a terminated upload.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file is missing or cannot be removed.
+    async fn remove_file(&self, file_info: &FileInfo) -> RustusResult<()>;
+}
@@ -0,0 +1,2 @@
+pub mod null_storage;
+pub mod sftp_storage;
@@ -1,10 +1,12 @@
 use std::path::PathBuf;
 
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 
+use axum::http::{header, HeaderMap, StatusCode};
 use axum::response::{IntoResponse, Response};
 use bytes::Bytes;
 use std::fs::DirBuilder;
+use tokio_util::io::ReaderStream;
 
 use crate::{
     data_storage::base::Storage,
@@ -13,6 +15,48 @@ use crate::{
     utils::{dir_struct::substr_now, headers::HeaderMapExt},
 };
 
+/// Parsed, resolved byte range requested by a client.
+///
+/// Bounds are inclusive, mirroring the semantics of the HTTP `Range` header.
+pub(crate) struct ByteRange {
+    pub(crate) start: u64,
+    pub(crate) end: u64,
+}
+
+/// Parse a single-range `Range: bytes=...` header against a known total size.
+///
+/// Supports the `start-end`, open-ended `start-` and suffix `-len` forms.
+/// Returns `None` when there is no usable range (caller should send the full
+/// body) and `Some(Err(()))` when the range is syntactically fine but cannot
+/// be satisfied (caller should answer `416`).
+pub(crate) fn parse_byte_range(value: Option<&str>, total: u64) -> Option<Result<ByteRange, ()>> {
+    let raw = value?.trim();
+    let spec = raw.strip_prefix("bytes=")?;
+    // We only honour the first range of a potentially comma-separated list.
+    let first = spec.split(',').next()?.trim();
+    let (start_str, end_str) = first.split_once('-')?;
+    let (start, end) = if start_str.is_empty() {
+        // Suffix form: `-len` -> last `len` bytes.
+        let len: u64 = end_str.parse().ok()?;
+        if len == 0 {
+            return Some(Err(()));
+        }
+        (total.saturating_sub(len), total.saturating_sub(1))
+    } else {
+        let start: u64 = start_str.parse().ok()?;
+        let end = if end_str.is_empty() {
+            total.saturating_sub(1)
+        } else {
+            end_str.parse::<u64>().ok()?.min(total.saturating_sub(1))
+        };
+        (start, end)
+    };
+    if total == 0 || start >= total || start > end {
+        return Some(Err(()));
+    }
+    Some(Ok(ByteRange { start, end }))
+}
+
 #[derive(Clone, Debug)]
 pub struct NullStorage {
     data_dir: PathBuf,
@@ -63,17 +107,83 @@ impl Storage for NullStorage {
         Ok(())
     }
 
-    async fn get_contents(&self, file_info: &FileInfo) -> RustusResult<Response> {
-        if file_info.path.is_none() {
+    async fn get_contents(&self, file_info: &FileInfo, headers: &HeaderMap) -> RustusResult<Response> {
+        let Some(path) = &file_info.path else {
             return Err(RustusError::FileNotFound);
         };
-        let mut resp = axum::body::Body::empty().into_response();
-        resp.headers_mut()
-            .generate_disposition(file_info.get_filename());
-        Ok(resp)
+        let mut file = tokio::fs::File::open(path).await?;
+        let total = file.metadata().await?.len();
+
+        match parse_byte_range(
+            headers.get(header::RANGE).and_then(|v| v.to_str().ok()),
+            total,
+        ) {
+            // Satisfiable range -> 206 Partial Content with the requested slice.
+            Some(Ok(range)) => {
+                let length = range.end - range.start + 1;
+                file.seek(std::io::SeekFrom::Start(range.start)).await?;
+                let stream = ReaderStream::new(file.take(length));
+                let mut resp = axum::body::Body::from_stream(stream).into_response();
+                *resp.status_mut() = StatusCode::PARTIAL_CONTENT;
+                resp.headers_mut().insert(header::ACCEPT_RANGES, "bytes".parse().unwrap());
+                resp.headers_mut().insert(
+                    header::CONTENT_RANGE,
+                    format!("bytes {}-{}/{total}", range.start, range.end)
+                        .parse()
+                        .unwrap(),
+                );
+                resp.headers_mut()
+                    .insert(header::CONTENT_LENGTH, length.into());
+                resp.headers_mut()
+                    .generate_disposition(file_info.get_filename());
+                Ok(resp)
+            }
+            // Present but unsatisfiable range -> 416.
+            Some(Err(())) => {
+                let mut resp = axum::body::Body::empty().into_response();
+                *resp.status_mut() = StatusCode::RANGE_NOT_SATISFIABLE;
+                resp.headers_mut()
+                    .insert(header::CONTENT_RANGE, format!("bytes */{total}").parse().unwrap());
+                Ok(resp)
+            }
+            // No range -> full body, advertising range support.
+            None => {
+                let stream = ReaderStream::new(file);
+                let mut resp = axum::body::Body::from_stream(stream).into_response();
+                resp.headers_mut().insert(header::ACCEPT_RANGES, "bytes".parse().unwrap());
+                resp.headers_mut()
+                    .insert(header::CONTENT_LENGTH, total.into());
+                resp.headers_mut()
+                    .generate_disposition(file_info.get_filename());
+                Ok(resp)
+            }
+        }
     }
 
-    async fn add_bytes(&self, _file_info: &FileInfo, mut bytes: Bytes) -> RustusResult<()> {
+    async fn add_bytes(&self, file_info: &FileInfo, mut bytes: Bytes) -> RustusResult<()> {
+        let Some(path) = &file_info.path else {
+            return Err(RustusError::FileNotFound);
+        };
+        // Write the chunk at the offset the upload bookkeeping records, rather
+        // than blindly appending. This is what makes a resumed upload crash
+        // safe: if a previous attempt crashed mid-write it may have left partial
+        // trailing bytes past the persisted offset, so we seek back to that
+        // offset, overwrite from there and `set_len` to drop anything beyond the
+        // chunk we just wrote. `sync_data` then persists the bytes and the new
+        // length before we return (the caller only advances the stored offset on
+        // `Ok`). It is O(chunk), never rewriting bytes earlier chunks persisted;
+        // the temp-file + rename pattern only fits writing a complete object at
+        // once and would be O(file) per chunk here.
+        let offset = file_info.offset as u64;
+        let mut opened = tokio::fs::OpenOptions::new()
+            .write(true)
+            .open(path.as_str())
+            .await?;
+        opened.seek(std::io::SeekFrom::Start(offset)).await?;
+        opened.write_all(&bytes).await?;
+        opened.set_len(offset + bytes.len() as u64).await?;
+        opened.flush().await?;
+        opened.sync_data().await?;
         bytes.clear();
         Ok(())
     }
@@ -0,0 +1,168 @@
+use bytes::Bytes;
+
+use crate::{errors::RustusError, models::file_info::FileInfo};
+
+/// Number of leading bytes buffered before an upload is classified.
+///
+/// The longest signature we sniff is a handful of bytes, but we keep a little
+/// headroom so a tiny first chunk doesn't force a premature, wrong verdict.
+const SNIFF_WINDOW: usize = 16;
+
+/// A content type recognised by [`sniff`]. Its [`mime`](DetectedType::mime)
+/// string is the value operators list in the `allowed_types` config (e.g.
+/// `image/jpeg`), and what [`ContentTypeValidator::validate`] matches against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DetectedType {
+    Jpeg,
+    Png,
+    Gif,
+    Pdf,
+    Webp,
+}
+
+impl DetectedType {
+    /// The canonical MIME type, used to cross-check the declared `Content-Type`.
+    #[must_use]
+    pub fn mime(self) -> &'static str {
+        match self {
+            DetectedType::Jpeg => "image/jpeg",
+            DetectedType::Png => "image/png",
+            DetectedType::Gif => "image/gif",
+            DetectedType::Pdf => "application/pdf",
+            DetectedType::Webp => "image/webp",
+        }
+    }
+}
+
+/// Classify a buffer by its leading magic bytes.
+///
+/// Returns `None` when the signature is unknown or not enough bytes have
+/// accumulated yet to decide.
+#[must_use]
+pub fn sniff(bytes: &[u8]) -> Option<DetectedType> {
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        return Some(DetectedType::Jpeg);
+    }
+    if bytes.starts_with(&[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A]) {
+        return Some(DetectedType::Png);
+    }
+    if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        return Some(DetectedType::Gif);
+    }
+    if bytes.starts_with(b"%PDF") {
+        return Some(DetectedType::Pdf);
+    }
+    // RIFF container whose form type is WEBP.
+    if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        return Some(DetectedType::Webp);
+    }
+    None
+}
+
+/// Magic-byte validator that rejects uploads outside a configured allowlist.
+///
+/// It is backend-agnostic: the protocol layer feeds it the first chunk(s)
+/// before handing them to [`Storage::add_bytes`](crate::data_storage::base::Storage::add_bytes).
+#[derive(Clone, Debug, Default)]
+pub struct ContentTypeValidator {
+    allowed_types: Vec<String>,
+}
+
+impl ContentTypeValidator {
+    #[must_use]
+    pub fn new(allowed_types: Vec<String>) -> ContentTypeValidator {
+        ContentTypeValidator { allowed_types }
+    }
+
+    /// Whether validation is switched on (a non-empty allowlist).
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        !self.allowed_types.is_empty()
+    }
+
+    /// Validate the start of an upload against the allowlist.
+    ///
+    /// `accumulated` is every byte seen so far for this upload; once the sniff
+    /// window is filled (or the upload is smaller than the window and complete)
+    /// the detected type must appear in `allowed_types`, otherwise the upload
+    /// is aborted with a [`RustusError::UnsupportedContentType`] that surfaces
+    /// as `415 Unsupported Media Type`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the sniffed type is not allowed, or when a
+    /// declared `Content-Type` contradicts the sniffed one.
+    pub fn validate(&self, file_info: &FileInfo, accumulated: &Bytes) -> Result<(), RustusError> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        // Wait for a full window unless the whole upload is smaller than it.
+        let complete = file_info
+            .length
+            .is_some_and(|len| accumulated.len() as u64 >= len);
+        if accumulated.len() < SNIFF_WINDOW && !complete {
+            return Ok(());
+        }
+
+        let Some(detected) = sniff(accumulated) else {
+            return Err(RustusError::UnsupportedContentType);
+        };
+        if !self
+            .allowed_types
+            .iter()
+            .any(|allowed| allowed == detected.mime())
+        {
+            return Err(RustusError::UnsupportedContentType);
+        }
+
+        // Optionally cross-check the client's declared type against the bytes.
+        if let Some(declared) = file_info
+            .metadata
+            .get("filetype")
+            .or_else(|| file_info.metadata.get("content-type"))
+        {
+            if declared != detected.mime() {
+                return Err(RustusError::UnsupportedContentType);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sniff, ContentTypeValidator, DetectedType};
+    use crate::models::file_info::FileInfo;
+    use bytes::Bytes;
+
+    #[test]
+    fn test_sniff_known_signatures() {
+        assert_eq!(sniff(&[0xFF, 0xD8, 0xFF, 0xE0]), Some(DetectedType::Jpeg));
+        assert_eq!(sniff(b"%PDF-1.7"), Some(DetectedType::Pdf));
+        assert_eq!(sniff(b"GIF89a....."), Some(DetectedType::Gif));
+        assert_eq!(sniff(b"not a known file"), None);
+    }
+
+    #[test]
+    fn test_disabled_validator_accepts_anything() {
+        let validator = ContentTypeValidator::new(vec![]);
+        let info = FileInfo::new_test();
+        assert!(validator.validate(&info, &Bytes::from_static(b"whatever")).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_type_outside_allowlist() {
+        let validator = ContentTypeValidator::new(vec!["image/png".to_string()]);
+        let info = FileInfo::new_test();
+        let jpeg = Bytes::from_static(&[0xFF, 0xD8, 0xFF, 0xE0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(validator.validate(&info, &jpeg).is_err());
+    }
+
+    #[test]
+    fn test_accepts_allowed_type() {
+        let validator = ContentTypeValidator::new(vec!["image/jpeg".to_string()]);
+        let info = FileInfo::new_test();
+        let jpeg = Bytes::from_static(&[0xFF, 0xD8, 0xFF, 0xE0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(validator.validate(&info, &jpeg).is_ok());
+    }
+}
@@ -0,0 +1,113 @@
+use std::str::FromStr;
+
+use base64::Engine;
+
+use crate::errors::{RustusError, RustusResult};
+
+/// A checksum algorithm rustus can advertise through the Checksum extension and
+/// verify against the client-supplied `Upload-Checksum` header.
+///
+/// The enabled set is driven by config, letting operators disable the weaker
+/// algorithms (md5, sha1) and opt into the modern ones (crc32c, blake3).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    Md5,
+    Sha1,
+    Sha256,
+    Sha512,
+    Crc32c,
+    Blake3,
+}
+
+impl ChecksumAlgorithm {
+    /// The token used both in `Tus-Checksum-Algorithm` and in the
+    /// `Upload-Checksum` header's algorithm field.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Md5 => "md5",
+            ChecksumAlgorithm::Sha1 => "sha1",
+            ChecksumAlgorithm::Sha256 => "sha256",
+            ChecksumAlgorithm::Sha512 => "sha512",
+            ChecksumAlgorithm::Crc32c => "crc32c",
+            ChecksumAlgorithm::Blake3 => "blake3",
+        }
+    }
+
+    /// Parse an algorithm token, e.g. from config or the `Upload-Checksum` header.
+    #[must_use]
+    pub fn parse_token(value: &str) -> Option<ChecksumAlgorithm> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "md5" => Some(ChecksumAlgorithm::Md5),
+            "sha1" => Some(ChecksumAlgorithm::Sha1),
+            "sha256" => Some(ChecksumAlgorithm::Sha256),
+            "sha512" => Some(ChecksumAlgorithm::Sha512),
+            "crc32c" => Some(ChecksumAlgorithm::Crc32c),
+            "blake3" => Some(ChecksumAlgorithm::Blake3),
+            _ => None,
+        }
+    }
+
+    /// Compute the digest of `data` with this algorithm.
+    #[must_use]
+    pub(crate) fn digest(self, data: &[u8]) -> Vec<u8> {
+        match self {
+            ChecksumAlgorithm::Md5 => md5::compute(data).0.to_vec(),
+            ChecksumAlgorithm::Sha1 => {
+                ring::digest::digest(&ring::digest::SHA1_FOR_LEGACY_USE_ONLY, data)
+                    .as_ref()
+                    .to_vec()
+            }
+            ChecksumAlgorithm::Sha256 => {
+                ring::digest::digest(&ring::digest::SHA256, data).as_ref().to_vec()
+            }
+            ChecksumAlgorithm::Sha512 => {
+                ring::digest::digest(&ring::digest::SHA512, data).as_ref().to_vec()
+            }
+            ChecksumAlgorithm::Crc32c => crc32c::crc32c(data).to_be_bytes().to_vec(),
+            ChecksumAlgorithm::Blake3 => blake3::hash(data).as_bytes().to_vec(),
+        }
+    }
+
+    /// Default advertised set, matching the historic hardcoded list.
+    #[must_use]
+    pub fn default_set() -> Vec<ChecksumAlgorithm> {
+        vec![
+            ChecksumAlgorithm::Md5,
+            ChecksumAlgorithm::Sha1,
+            ChecksumAlgorithm::Sha256,
+            ChecksumAlgorithm::Sha512,
+        ]
+    }
+}
+
+impl FromStr for ChecksumAlgorithm {
+    type Err = String;
+
+    /// Parse an algorithm token for config/CLI value parsing.
+    fn from_str(value: &str) -> Result<ChecksumAlgorithm, String> {
+        ChecksumAlgorithm::parse_token(value)
+            .ok_or_else(|| format!("unknown checksum algorithm: {value}"))
+    }
+}
+
+impl ChecksumAlgorithm {
+    /// Verify that the base64-encoded `expected` digest matches `data`.
+    ///
+    /// `expected` is the base64 text taken verbatim from the `Upload-Checksum`
+    /// header's checksum field; it is decoded here and compared against the raw
+    /// digest in constant time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RustusError::WrongChecksum`] (surfaced as `460 Checksum
+    /// Mismatch`) when `expected` is not valid base64 or the computed digest
+    /// differs from the supplied one.
+    pub fn verify(self, data: &[u8], expected: &[u8]) -> RustusResult<()> {
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(expected)
+            .map_err(|_| RustusError::WrongChecksum)?;
+        ring::constant_time::verify_slices_are_equal(&self.digest(data), &decoded)
+            .map_err(|_| RustusError::WrongChecksum)
+    }
+}
@@ -0,0 +1,22 @@
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::response::Response;
+
+use crate::{errors::RustusResult, data_storage::base::Storage, state::RustusState};
+
+/// `GET /files/{upload_id}` — serve the stored bytes of a completed upload.
+///
+/// The request `HeaderMap` is forwarded to [`Storage::get_contents`] so the
+/// backend can honour a `Range` request and answer `206 Partial Content`.
+#[allow(clippy::unused_async)]
+pub async fn get_file(
+    State(state): State<RustusState>,
+    Path(upload_id): Path<String>,
+    headers: HeaderMap,
+) -> RustusResult<Response> {
+    let file_info = state.info_storage.get_info(upload_id.as_str()).await?;
+    state
+        .data_storage
+        .get_contents(&file_info, &headers)
+        .await
+}
@@ -18,7 +18,16 @@ pub async fn server_info(state: web::Data<State>) -> HttpResponse {
     response_builder.insert_header(("Tus-Extension", ext_str.as_str()));
     #[cfg(feature = "hashers")]
     if state.config.tus_extensions.contains(&Extensions::Checksum) {
-        response_builder.insert_header(("Tus-Checksum-Algorithm", "md5,sha1,sha256,sha512"));
+        // Advertise exactly the configured, enabled algorithm set so operators
+        // can disable weak algorithms (md5/sha1) or enable crc32c/blake3.
+        let algorithms = state
+            .config
+            .checksum_algorithms
+            .iter()
+            .map(|algo| algo.as_str())
+            .collect::<Vec<&str>>()
+            .join(",");
+        response_builder.insert_header(("Tus-Checksum-Algorithm", algorithms.as_str()));
     }
     response_builder.finish()
 }
@@ -0,0 +1,50 @@
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::response::{IntoResponse, Response};
+use bytes::Bytes;
+
+use crate::{
+    data_storage::base::Storage,
+    errors::{RustusError, RustusResult},
+    protocol::core::checksum::ChecksumAlgorithm,
+    state::RustusState,
+};
+
+/// `PATCH /files/{upload_id}` — append the request body to an upload.
+///
+/// Before the bytes reach the backend we sniff the leading bytes against the
+/// configured content-type allowlist; a disallowed type aborts the upload with
+/// [`RustusError::UnsupportedContentType`](crate::errors::RustusError), which
+/// surfaces as `415 Unsupported Media Type`.
+pub async fn write_bytes(
+    State(state): State<RustusState>,
+    Path(upload_id): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> RustusResult<Response> {
+    let file_info = state.info_storage.get_info(upload_id.as_str()).await?;
+
+    // Content-type validation runs here, before `Storage::add_bytes`, so it is
+    // backend-agnostic. Only the first chunk carries the leading bytes we sniff,
+    // so we skip it once the upload is past its start.
+    let validator = state.config.content_type_validator();
+    if validator.is_enabled() && file_info.offset == 0 {
+        validator.validate(&file_info, &body)?;
+    }
+
+    // Checksum extension: verify the chunk against the client-declared digest.
+    // The header is `Upload-Checksum: <algorithm> <base64(checksum)>`.
+    if let Some(value) = headers.get("upload-checksum") {
+        let value = value.to_str().map_err(|_| RustusError::WrongChecksum)?;
+        let (algo, checksum) = value.split_once(' ').ok_or(RustusError::WrongChecksum)?;
+        let algorithm = ChecksumAlgorithm::parse_token(algo).ok_or(RustusError::WrongChecksum)?;
+        // Only honour algorithms the operator has enabled.
+        if !state.config.checksum_algorithms.contains(&algorithm) {
+            return Err(RustusError::WrongChecksum);
+        }
+        algorithm.verify(&body, checksum.as_bytes())?;
+    }
+
+    state.data_storage.add_bytes(&file_info, body).await?;
+    Ok(axum::http::StatusCode::NO_CONTENT.into_response())
+}